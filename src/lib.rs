@@ -36,12 +36,16 @@
 use std::{
     collections::BTreeMap,
     fmt::{Debug, Display},
-    io::Write,
+    io::{BufRead, Read, Write},
+    str::FromStr,
 };
 
 /// Import this to include all necessary `prsm` features to get your script manager up and running.
 pub mod prelude {
-    pub use crate::{prsm, PrsmDisplay, ScriptManager};
+    pub use crate::{
+        prompt, prsm, prsm_script, Entry, ParamSpec, PrsmDisplay, Script, ScriptManager,
+        ScriptMeta,
+    };
 }
 
 /// A displayable type that can be both displayed (for error handling) and debugged (for unwrapping).
@@ -52,6 +56,10 @@ impl<T> PrsmDisplay for T where T: Display + Debug {}
 /// as one that does not fail, please use [`Infallible`](::std::convert::Infallible).
 pub type ScriptResult = Result<(), Box<dyn PrsmDisplay>>;
 
+/// A script's deferred body, receiving the values collected for its declared
+/// [`ScriptMeta::params`] (empty if it declares none). See [`Script::new`].
+pub type ScriptFn = Box<dyn Fn(&[String]) -> ScriptResult>;
+
 /// A `prsm` script that can be called through the [`ScriptManager`].
 ///
 /// These should almost never be manually constructed, and should instead be constructed through
@@ -76,14 +84,16 @@ pub type ScriptResult = Result<(), Box<dyn PrsmDisplay>>;
 /// fn foo() -> Result<(), &'static str> { Err("this function failed!") }
 /// let script: Script = Script::new(
 ///     "This is a test function",
-///     Box::new(move || foo().map_err(|e| Box::new(e) as Box<dyn PrsmDisplay>)),
+///     Box::new(move |_params: &[String]| foo().map_err(|e| Box::new(e) as Box<dyn PrsmDisplay>)),
 /// );
 /// ```
 ///
-/// Note that despite [`Script::new`] requiring a function with an empty parameter list, this is
-/// *only* for the boxed closure that wraps around the function. In other words, your scripts *can
-/// take arguments*, and their function calls are deferred until they are run using the
-/// [`run`](Script::run) method.
+/// Note that despite [`Script::new`] requiring a closure that takes a slice of collected
+/// parameter values, this is *only* relevant for scripts that declare [`ScriptMeta::params`] and
+/// are run through [`run_interactive`](ScriptManager::run_interactive) — everything else, the
+/// slice is empty and can be ignored, as above. In other words, your scripts *can take
+/// arguments*, and their function calls are deferred until they are run using the
+/// [`run`](Script::run)/[`run_interactive`](Script::run_interactive) methods.
 ///
 /// **Function Parameters**
 ///
@@ -105,22 +115,265 @@ pub type ScriptResult = Result<(), Box<dyn PrsmDisplay>>;
 pub struct Script<'a> {
     /// The script description that will appear in the [`ScriptManager`] menu dialog.
     pub description: &'a str,
-    func: Box<dyn Fn() -> ScriptResult>,
+    meta: ScriptMeta<'a>,
+    func: ScriptFn,
 }
 
 impl<'a> Script<'a> {
     /// Construct a named script that returns a [`ScriptResult`]. Manually creating a script is
     /// ill-advised since misuse could lead to a loss of data in the return. Unless you
     /// specifically need an individual script instance, consider using the [`prsm`] macro instead.
-    pub fn new(description: &'a str, func: Box<dyn Fn() -> ScriptResult>) -> Self {
-        Script { description, func }
+    ///
+    /// `func` receives the values collected for this script's declared [`ScriptMeta::params`] (in
+    /// declaration order) when run through [`run_interactive`](Script::run_interactive) — an
+    /// empty slice otherwise, including for every script with no declared params.
+    pub fn new(description: &'a str, func: ScriptFn) -> Self {
+        Script {
+            description,
+            meta: ScriptMeta::default(),
+            func,
+        }
+    }
+
+    /// Attach [`ScriptMeta`] to this script, replacing any metadata it was constructed with.
+    /// This is wired up by the [`prsm`]/[`prsm_script`] macros when a metadata block is supplied
+    /// and is not usually called directly.
+    pub fn with_meta(mut self, meta: ScriptMeta<'a>) -> Self {
+        self.meta = meta;
+        self
     }
 
-    /// Run the script. The error type will be morphed into a displayable item rather than the
-    /// original type that was provided when creating the script instance.
+    /// Run the script with no collected parameters. The error type will be morphed into a
+    /// displayable item rather than the original type that was provided when creating the script
+    /// instance.
+    ///
+    /// A script that declares [`ScriptMeta::params`] must instead be run through
+    /// [`run_interactive`](Script::run_interactive) (or, from a [`ScriptManager`], through
+    /// [`run_interactive`](ScriptManager::run_interactive)) so its parameters are actually
+    /// collected; calling `run` on one returns an error rather than invoking its closure with no
+    /// values, which would otherwise panic the moment the closure indexes into them.
     pub fn run(&self) -> ScriptResult {
-        (self.func)()
+        if !self.meta.params.is_empty() {
+            return Err(Box::new(
+                "this script declares params and must be run via `run_interactive`, not `run`"
+                    .to_string(),
+            ) as Box<dyn PrsmDisplay>);
+        }
+
+        (self.func)(&[])
+    }
+
+    /// Run the script with `params` — the values collected for its declared
+    /// [`ScriptMeta::params`], in the same order — passed through to the underlying closure.
+    /// Used by [`ScriptManager::run_interactive`]; call it directly only if you're collecting
+    /// parameters yourself instead of going through the manager.
+    pub fn run_interactive(&self, params: &[String]) -> ScriptResult {
+        (self.func)(params)
     }
+
+    /// Whether this script should be selected when a caller looks it up by name, i.e. `name` is
+    /// a case-insensitive substring of [`description`](Script::description) or matches one of its
+    /// [`ScriptMeta::aliases`] in full, ignoring ASCII case. Used by
+    /// [`ScriptManager::dispatch_by_name`].
+    fn matches_name(&self, name: &str) -> bool {
+        self.description.to_lowercase().contains(&name.to_lowercase())
+            || self
+                .meta
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Optional metadata describing a [`Script`] beyond its compact menu `description`, surfaced
+/// through [`ScriptManager::help`]/[`ScriptManager::print_help`]. Supply it through the [`prsm`]/
+/// [`prsm_script`] macros' metadata block rather than constructing it directly.
+///
+/// # Examples
+/// ```rust
+/// use prsm::{prsm_script, Script};
+///
+/// fn format() -> Result<(), std::io::Error> { Ok(()) }
+///
+/// let script: Script = prsm_script!(
+///     "Format",
+///     format(),
+///     { category: "build", help: "Runs rustfmt over the workspace", aliases: &["fmt"] }
+/// );
+///
+/// assert_eq!(script.description, "Format");
+/// ```
+#[derive(Default, Clone)]
+pub struct ScriptMeta<'a> {
+    /// A longer description rendered by [`ScriptManager::help`]/[`ScriptManager::print_help`] in
+    /// addition to the compact menu description.
+    pub help: Option<&'a str>,
+    /// A category/group label used to organize the detailed help view.
+    pub category: Option<&'a str>,
+    /// Alternate names this script can be invoked by, e.g. through
+    /// [`ScriptManager::dispatch_by_name`].
+    pub aliases: &'a [&'a str],
+    /// Declares the runtime-prompted parameters (if any) this script collects. Surfaced through
+    /// [`ScriptManager::help`], and driven end-to-end by [`ScriptManager::run_interactive`]: each
+    /// declared [`ParamSpec`] is prompted for over whatever input/output was supplied (re-prompting
+    /// on an invalid value) before the script's closure is invoked with the collected values, in
+    /// order. A script with a non-empty `params` should be declared with the params-first form of
+    /// the [`prsm`]/[`prsm_script`] macros' metadata block; see [`prsm_script`] for the syntax.
+    pub params: &'a [ParamSpec<'a>],
+}
+
+/// Describes a single runtime-prompted parameter a script collects through
+/// [`ScriptManager::run_interactive`], for both introspection (via [`ScriptMeta::params`]) and
+/// the actual collection itself. Supply it through the [`prsm`]/[`prsm_script`] macros' metadata
+/// block rather than constructing it directly.
+#[derive(Clone, Copy)]
+pub struct ParamSpec<'a> {
+    /// The parameter's name, shown alongside `prompt` and in [`ScriptManager::help`].
+    pub name: &'a str,
+    /// A human-readable description of what the parameter is used for.
+    pub prompt: &'a str,
+    /// Checks whether a raw line of input is an acceptable value for this parameter, e.g.
+    /// `|s| s.parse::<usize>().map(|_| ()).map_err(|e| e.to_string())`. On `Err`, the message is
+    /// shown to the user and the parameter is re-prompted; nothing is handed to the script until
+    /// every declared parameter passes.
+    pub validate: fn(&str) -> Result<(), String>,
+}
+
+/// Prompt the user on stdin for a value named `name`, parsing it via [`FromStr`]. On a parse
+/// failure, the error is printed and the prompt repeats until a valid value is entered.
+///
+/// This is a standalone convenience for ad hoc input a script wants beyond what it declares
+/// through [`ScriptMeta::params`] — it is not how declared parameters are collected. Declared
+/// parameters are read and checked against their [`ParamSpec::validate`] by
+/// [`ScriptManager::run_interactive`] itself, then handed to the script as strings (through whatever
+/// input/output was supplied), which is what lets a parameterized script be driven through
+/// scripted input the same way [`run_with`](ScriptManager::run_with)/
+/// [`run_loop_with`](ScriptManager::run_loop_with) drive the menu itself. `prompt`, by contrast,
+/// always reads from and writes to the real [`std::io::stdin`]/[`std::io::stdout`] directly and
+/// needs a real interactive terminal (or real stdin/stdout) to run correctly, same as
+/// [`ScriptManager::run`]/[`run_loop`](ScriptManager::run_loop) do.
+///
+/// If stdin is closed or exhausted (e.g. it was redirected from `/dev/null`, or piped input ran
+/// out), `read_line` reports `Ok(0)` without ever filling `buf`. Looping on that would spin
+/// forever re-printing the prompt, so `prompt` treats end-of-stream as fatal, same as the other
+/// stdio failures in this function: there's no value to return and no terminal to retry against.
+///
+/// # Examples
+/// ```rust,no_run
+/// use prsm::{prompt, prsm_script};
+///
+/// fn greet(name: String) -> Result<(), std::convert::Infallible> {
+///     println!("Hello, {}!", name);
+///     Ok(())
+/// }
+///
+/// let script = prsm_script!("Greet", greet(prompt::<String>("name")));
+/// script.run().unwrap();
+/// ```
+pub fn prompt<T>(name: &str) -> T
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    loop {
+        print!("{}: ", name);
+        std::io::stdout()
+            .flush()
+            .expect("should be able to flush output buffer");
+
+        let mut buf = String::new();
+        let bytes_read = std::io::stdin()
+            .read_line(&mut buf)
+            .expect("can read input from user");
+
+        if bytes_read == 0 {
+            panic!("no value for '{}': stdin closed", name);
+        }
+
+        match buf.trim().parse::<T>() {
+            Ok(value) => return value,
+            Err(e) => println!("invalid value for '{}': {}\n", name, e),
+        }
+    }
+}
+
+/// A [`BufRead`] over the real, process-wide stdin used by [`ScriptManager::run`]/
+/// [`run_loop`](ScriptManager::run_loop) instead of a plain [`BufReader`](std::io::BufReader) or a
+/// held [`StdinLock`](std::io::StdinLock).
+///
+/// Both of those alternatives are broken for this crate's purposes:
+/// - A held `StdinLock` deadlocks as soon as a selected script reads stdin itself (e.g. via
+///   [`prompt`]), since stdin's lock isn't reentrant.
+/// - A plain `BufReader::new(std::io::stdin())` avoids the deadlock, but reads ahead into its own
+///   private buffer. When stdin is fed in one batch (piped input, as is common for CI/Makefile
+///   usage), that buffer can silently swallow bytes meant for a later `prompt` call — the menu's
+///   single `read_line` may read past its own line into lines the script hasn't asked for yet.
+///
+/// `StdinSource` instead re-acquires the real [`Stdin`](std::io::Stdin)'s own lock for every
+/// `read_line`, so it draws from the same process-wide buffered stream that `prompt` reads from,
+/// never holding the lock longer than a single line and never stealing bytes into a buffer of its
+/// own.
+struct StdinSource;
+
+impl Read for StdinSource {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        std::io::stdin().lock().read(buf)
+    }
+}
+
+impl BufRead for StdinSource {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        unreachable!("StdinSource is only ever driven through the read_line override below")
+    }
+
+    fn consume(&mut self, _amt: usize) {
+        unreachable!("StdinSource is only ever driven through the read_line override below")
+    }
+
+    fn read_line(&mut self, buf: &mut String) -> std::io::Result<usize> {
+        std::io::stdin().read_line(buf)
+    }
+}
+
+/// Collect one validated value per declared [`ParamSpec`] in `specs`, reading from `input` and
+/// writing prompts/errors to `output`. Re-prompts on a value that fails its
+/// [`validate`](ParamSpec::validate) check. Returns an error (without panicking or looping
+/// forever, unlike [`prompt`]) if `input` hits EOF before every parameter is collected.
+fn collect_params(
+    specs: &[ParamSpec],
+    input: &mut dyn BufRead,
+    output: &mut dyn Write,
+) -> Result<Vec<String>, String> {
+    let mut collected = Vec::with_capacity(specs.len());
+
+    for spec in specs {
+        loop {
+            write!(output, "{} ({}): ", spec.name, spec.prompt)
+                .expect("should be able to write to output");
+            output.flush().expect("should be able to flush output buffer");
+
+            let mut buf = String::new();
+            let bytes_read = input.read_line(&mut buf).expect("can read input from user");
+            if bytes_read == 0 {
+                return Err(format!(
+                    "no value given for '{}': input closed",
+                    spec.name
+                ));
+            }
+
+            let value = buf.trim().to_string();
+            match (spec.validate)(&value) {
+                Ok(()) => {
+                    collected.push(value);
+                    break;
+                }
+                Err(e) => writeln!(output, "invalid value for '{}': {}\n", spec.name, e)
+                    .expect("should be able to write to output"),
+            }
+        }
+    }
+
+    Ok(collected)
 }
 
 /// A named script manager (defaults to "ScriptManager"). When ran, the manager displays a mapping
@@ -165,7 +418,7 @@ impl<'a> Script<'a> {
 ///
 /// ```rust
 /// use std::collections::BTreeMap;
-/// use prsm::{prsm_script, ScriptManager};
+/// use prsm::{prsm_script, Entry, ScriptManager};
 ///
 /// fn format() -> Result<(), std::io::Error> { Ok(()) }
 /// fn lint() -> Result<(), std::io::Error> { Ok(()) }
@@ -173,8 +426,8 @@ impl<'a> Script<'a> {
 /// let manual_sm: ScriptManager = ScriptManager::new(
 ///     Some("ManualManager"),
 ///     BTreeMap::from_iter([
-///         (1, prsm_script!("Format repository files", format())),
-///         (2, prsm_script!("Lint Rust files", lint())),
+///         (1, Entry::Script(prsm_script!("Format repository files", format()))),
+///         (2, Entry::Script(prsm_script!("Lint Rust files", lint()))),
 ///     ]),
 /// );
 ///
@@ -201,12 +454,44 @@ impl<'a> Script<'a> {
 /// };
 ///
 /// script_manager.run();
-/// ```  
+/// ```
+///
+/// # Nested groups
+/// Scripts can be organized into named sub-menus with the `group` form of the [`prsm`] macro.
+/// Selecting a group's ID enters its submenu; exiting that submenu (via the `q`/empty-line
+/// sentinel in [`run_loop`](ScriptManager::run_loop)) returns to the parent menu rather than
+/// ending the session.
+///
+/// ```rust,no_run
+/// use prsm::prsm;
+///
+/// fn format() -> Result<(), std::io::Error> { Ok(()) }
+/// fn lint() -> Result<(), std::io::Error> { Ok(()) }
+///
+/// let script_manager = prsm! {
+///     [1] group "Build" {
+///         [1] "Format" => format(),
+///         [2] "Lint" => lint()
+///     }
+/// };
+///
+/// script_manager.run_loop();
+/// ```
 pub struct ScriptManager<'a> {
     /// The name of the script manager that's displayed when [`run`](ScriptManager::run) is called.
     pub name: &'a str,
 
-    scripts: BTreeMap<usize, Script<'a>>,
+    entries: BTreeMap<usize, Entry<'a>>,
+}
+
+/// A single slot in a [`ScriptManager`]'s menu: either a runnable [`Script`] or a nested
+/// [`ScriptManager`] acting as a named submenu. Constructed by the [`prsm`] macro; manual
+/// construction is only needed alongside [`ScriptManager::new`].
+pub enum Entry<'a> {
+    /// A leaf script that can be run directly.
+    Script(Script<'a>),
+    /// A nested group of scripts (and/or further groups) rendered as a submenu.
+    Group(ScriptManager<'a>),
 }
 
 impl<'a> Display for ScriptManager<'a> {
@@ -215,8 +500,11 @@ impl<'a> Display for ScriptManager<'a> {
         let footer = "=".repeat(header.len());
 
         writeln!(f, "{}", header)?;
-        for (idx, script) in &self.scripts {
-            writeln!(f, "[{}] {}", idx, script.description)?;
+        for (idx, entry) in &self.entries {
+            match entry {
+                Entry::Script(script) => writeln!(f, "[{}] {}", idx, script.description)?,
+                Entry::Group(group) => writeln!(f, "[{}] {} (group)", idx, group.name)?,
+            }
         }
         write!(f, "{}", footer)?;
 
@@ -225,44 +513,382 @@ impl<'a> Display for ScriptManager<'a> {
 }
 
 impl<'a> ScriptManager<'a> {
-    /// Construct a [`ScriptManager`] with the given name and scripts. The indices of the scripts
+    /// Construct a [`ScriptManager`] with the given name and entries. The indices of the entries
     /// in the map correlate to their option IDs that will be displayed when [`run`](ScriptManager::run)
     /// is called. If `None` is provided for `name`, then the default named "ScriptManager" is used.
     ///
     /// Although you can manually create a [`ScriptManager`] instance using this function, consider
     /// using the [`prsm`] macro instead.
-    pub fn new(name: Option<&'a str>, scripts: BTreeMap<usize, Script<'a>>) -> Self {
+    pub fn new(name: Option<&'a str>, entries: BTreeMap<usize, Entry<'a>>) -> Self {
         Self {
             name: match name {
                 Some(n) => n,
                 None => "ScriptManager",
             },
-            scripts,
+            entries,
         }
     }
 
-    /// Load the script manager's menu and then request the user for a script to be run. Any
+    /// Load the script manager's menu and then request the user for an entry to be run. Any
     /// errors in the chosen script are collected as a string and returned from this function
-    /// for logging.
+    /// for logging. Selecting a group entry enters its submenu (via its own [`run`](ScriptManager::run)).
+    ///
+    /// This is a convenience wrapper around [`run_with`](ScriptManager::run_with) that wires up
+    /// the real standard input and output streams, via [`StdinSource`] rather than a held
+    /// [`StdinLock`](std::io::StdinLock): [`prompt`] locks stdin again (briefly) every time it
+    /// reads a line, and since stdin's lock isn't reentrant, holding a lock across the call to a
+    /// selected script would deadlock as soon as that script uses [`prompt`]. See [`StdinSource`]
+    /// for why a plain [`BufReader`](std::io::BufReader) over stdin doesn't work either.
     pub fn run(&self) -> Result<(), String> {
-        println!("{}\n", self);
-        print!("Enter script ID: ");
-        std::io::stdout()
-            .flush()
-            .expect("should be able to flush output buffer");
+        self.run_with(StdinSource, std::io::stdout())
+    }
+
+    /// Like [`run`](ScriptManager::run), but reads from `input` and writes the menu/prompt to
+    /// `output` instead of the real standard input/output streams. This makes the interactive
+    /// flow deterministic to test: feed a [`BufRead`] over scripted input and inspect whatever
+    /// was written to an in-memory [`Write`] sink.
+    ///
+    /// This covers the menu prompt and the collection of a selected script's declared
+    /// [`ScriptMeta::params`]. A script that reads its own input via [`prompt`] still
+    /// reads/writes the real stdin/stdout directly regardless of what is passed here — see
+    /// [`prompt`]'s docs.
+    ///
+    /// ```rust
+    /// use prsm::prsm;
+    ///
+    /// fn format() -> Result<(), std::io::Error> { Ok(()) }
+    ///
+    /// let script_manager = prsm! { [1] "Format repository files" => format() };
+    ///
+    /// let input = std::io::Cursor::new(b"1\n".to_vec());
+    /// let mut output = Vec::new();
+    /// let result = script_manager.run_with(input, &mut output);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn run_with<R: BufRead, W: Write>(&self, mut input: R, mut output: W) -> Result<(), String> {
+        self.run_with_dyn(&mut input, &mut output)
+    }
+
+    /// Does the actual work for [`run_with`](ScriptManager::run_with). Group recursion is done
+    /// through trait objects rather than staying generic over `R`/`W`, since a generic recursive
+    /// call would otherwise re-wrap the reader/writer in a new `&mut` layer on every nested group
+    /// and blow up monomorphization for deeply nested menus.
+    fn run_with_dyn(&self, input: &mut dyn BufRead, output: &mut dyn Write) -> Result<(), String> {
+        write!(output, "{}\n\n", self).expect("should be able to write to output");
+        write!(output, "Enter ID: ").expect("should be able to write to output");
+        output.flush().expect("should be able to flush output buffer");
 
         let mut buf = String::new();
-        std::io::stdin()
-            .read_line(&mut buf)
-            .expect("can read input from user");
+        input.read_line(&mut buf).expect("can read input from user");
 
         let opt = buf.trim().parse::<usize>().expect("user gave valid input");
-        self.scripts[&opt].run().map_err(|e| format!("{}", e))
+        match &self.entries[&opt] {
+            Entry::Script(script) => Self::run_entry_script(script, input, output),
+            Entry::Group(group) => group.run_with_dyn(input, output),
+        }
+    }
+
+    /// Runs `script` through `input`/`output`, collecting its declared [`ScriptMeta::params`]
+    /// first (re-prompting on an invalid value) if it has any, or running it as-is otherwise.
+    /// Shared by [`run_with_dyn`](ScriptManager::run_with_dyn) and
+    /// [`run_loop_with_dyn`](ScriptManager::run_loop_with_dyn) so menu-driven selection collects
+    /// parameters the same way [`run_interactive_with_dyn`](ScriptManager::run_interactive_with_dyn)
+    /// does.
+    fn run_entry_script(
+        script: &Script,
+        input: &mut dyn BufRead,
+        output: &mut dyn Write,
+    ) -> Result<(), String> {
+        if script.meta.params.is_empty() {
+            return script.run().map_err(|e| format!("{}", e));
+        }
+
+        let params = collect_params(script.meta.params, input, output)?;
+        script.run_interactive(&params).map_err(|e| format!("{}", e))
+    }
+
+    /// Like [`run`](ScriptManager::run), but instead of exiting after a single invocation, this
+    /// redisplays the menu and re-prompts until the user asks to quit. Errors returned by a
+    /// chosen script are printed using their `Display` impl rather than propagated, so a single
+    /// failing script does not end the session. Unknown IDs and unparseable input print a
+    /// friendly message and re-prompt rather than panicking. Entering `q` (case-insensitive) or
+    /// an empty line exits the loop, returning `Ok(())`.
+    ///
+    /// Selecting a group entry recurses into that group's own `run_loop`, so its submenu is
+    /// displayed until the user quits it with `q`/an empty line, at which point control returns
+    /// to this (parent) menu rather than ending the whole session.
+    ///
+    /// This is the entry point for using `prsm` as an interactive console rather than a one-shot
+    /// dispatcher.
+    ///
+    /// This is a convenience wrapper around [`run_loop_with`](ScriptManager::run_loop_with) that
+    /// wires up the real standard input and output streams. See [`run`](ScriptManager::run) for
+    /// why [`StdinSource`] is used here instead of a held [`StdinLock`](std::io::StdinLock).
+    pub fn run_loop(&self) -> Result<(), String> {
+        self.run_loop_with(StdinSource, std::io::stdout())
+    }
+
+    /// Like [`run_loop`](ScriptManager::run_loop), but reads from `input` and writes the
+    /// menu/prompt to `output` instead of the real standard input/output streams, making the full
+    /// interactive console loop (including group navigation and re-prompting) deterministic to
+    /// test with scripted input and a captured output buffer.
+    ///
+    /// This covers the menu prompt and the collection of a selected script's declared
+    /// [`ScriptMeta::params`]. A script that reads its own input via [`prompt`] still
+    /// reads/writes the real stdin/stdout directly regardless of what is passed here — see
+    /// [`prompt`]'s docs.
+    ///
+    /// ```rust
+    /// use prsm::prsm;
+    ///
+    /// fn format() -> Result<(), std::io::Error> { Ok(()) }
+    ///
+    /// let script_manager = prsm! { [1] "Format repository files" => format() };
+    ///
+    /// let input = std::io::Cursor::new(b"nope\n1\nq\n".to_vec());
+    /// let mut output = Vec::new();
+    /// let result = script_manager.run_loop_with(input, &mut output);
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn run_loop_with<R: BufRead, W: Write>(&self, mut input: R, mut output: W) -> Result<(), String> {
+        self.run_loop_with_dyn(&mut input, &mut output)
+    }
+
+    /// Does the actual work for [`run_loop_with`](ScriptManager::run_loop_with), recursing into
+    /// nested groups through trait objects for the same reason as
+    /// [`run_with_dyn`](ScriptManager::run_with_dyn).
+    fn run_loop_with_dyn(&self, input: &mut dyn BufRead, output: &mut dyn Write) -> Result<(), String> {
+        loop {
+            write!(output, "{}\n\n", self).expect("should be able to write to output");
+            write!(output, "Enter ID (or 'q' to quit): ").expect("should be able to write to output");
+            output.flush().expect("should be able to flush output buffer");
+
+            let mut buf = String::new();
+            input.read_line(&mut buf).expect("can read input from user");
+
+            let trimmed = buf.trim();
+            if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("q") {
+                return Ok(());
+            }
+
+            let opt = match trimmed.parse::<usize>() {
+                Ok(opt) => opt,
+                Err(_) => {
+                    writeln!(output, "'{}' is not a valid ID.\n", trimmed)
+                        .expect("should be able to write to output");
+                    continue;
+                }
+            };
+
+            match self.entries.get(&opt) {
+                Some(Entry::Script(script)) => {
+                    if let Err(e) = Self::run_entry_script(script, input, output) {
+                        writeln!(output, "{}\n", e).expect("should be able to write to output");
+                    }
+                }
+                Some(Entry::Group(group)) => {
+                    if let Err(e) = group.run_loop_with_dyn(input, output) {
+                        writeln!(output, "{}\n", e).expect("should be able to write to output");
+                    }
+                }
+                None => writeln!(output, "No entry with ID {} exists.\n", opt)
+                    .expect("should be able to write to output"),
+            }
+        }
+    }
+
+    /// Run the script with the given `id`, collecting any [`ScriptMeta::params`] it declares by
+    /// prompting for each one (re-prompting on an invalid value) before invoking it. Returns an
+    /// error if `id` does not name a script, or if collection is interrupted (e.g. `input` hits
+    /// EOF before every parameter is collected).
+    ///
+    /// This is a convenience wrapper around
+    /// [`run_interactive_with`](ScriptManager::run_interactive_with) that wires up the real
+    /// standard input and output streams, via [`StdinSource`] as in [`run`](ScriptManager::run).
+    pub fn run_interactive(&self, id: usize) -> Result<(), String> {
+        self.run_interactive_with(id, StdinSource, std::io::stdout())
+    }
+
+    /// Like [`run_interactive`](ScriptManager::run_interactive), but reads from `input` and
+    /// writes prompts/errors to `output` instead of the real standard input/output streams. This
+    /// makes parameter collection deterministic to test, the same way
+    /// [`run_with`](ScriptManager::run_with) does for the menu prompt.
+    ///
+    /// ```rust
+    /// use prsm::{prsm, ParamSpec};
+    ///
+    /// fn parse_check(n: usize) -> Result<(), std::convert::Infallible> {
+    ///     assert_eq!(n, 42);
+    ///     Ok(())
+    /// }
+    ///
+    /// let sm = prsm! {
+    ///     [1] "Parse num" {
+    ///         params: &[ParamSpec {
+    ///             name: "n_str",
+    ///             prompt: "a number to check",
+    ///             validate: |s| s.parse::<usize>().map(|_| ()).map_err(|e| e.to_string()),
+    ///         }]
+    ///     } => |params: &[String]| parse_check(params[0].parse().unwrap())
+    /// };
+    ///
+    /// let input = std::io::Cursor::new(b"not a number\n42\n".to_vec());
+    /// let mut output = Vec::new();
+    /// let result = sm.run_interactive_with(1, input, &mut output);
+    ///
+    /// assert!(result.is_ok());
+    /// let rendered = String::from_utf8(output).unwrap();
+    /// assert!(rendered.contains("invalid value for 'n_str'"));
+    /// ```
+    pub fn run_interactive_with<R: BufRead, W: Write>(
+        &self,
+        id: usize,
+        mut input: R,
+        mut output: W,
+    ) -> Result<(), String> {
+        self.run_interactive_with_dyn(id, &mut input, &mut output)
+    }
+
+    /// Does the actual work for
+    /// [`run_interactive_with`](ScriptManager::run_interactive_with), for the same reason as
+    /// [`run_with_dyn`](ScriptManager::run_with_dyn).
+    fn run_interactive_with_dyn(
+        &self,
+        id: usize,
+        input: &mut dyn BufRead,
+        output: &mut dyn Write,
+    ) -> Result<(), String> {
+        match self.entries.get(&id) {
+            Some(Entry::Script(script)) => Self::run_entry_script(script, input, output),
+            Some(Entry::Group(_)) => {
+                Err(format!("ID {} is a group and cannot be run interactively", id))
+            }
+            None => Err(format!("no script found for ID {}", id)),
+        }
     }
 
-    #[cfg(test)]
-    fn run_script(&self, idx: usize) -> Result<(), String> {
-        self.scripts[&idx].run().map_err(|e| format!("{}", e))
+    /// Dispatch a script non-interactively based on command-line style arguments, falling back
+    /// to [`run`](ScriptManager::run) when no argument is given. `args` is expected to hold a
+    /// single selector (typically `argv[1]`, e.g. `std::env::args().skip(1).collect::<Vec<_>>()`)
+    /// naming the script to invoke either by its numeric ID or by a description match, and is
+    /// forwarded to [`dispatch_by_name`](ScriptManager::dispatch_by_name).
+    ///
+    /// This lets a `prsm`-based tool be driven directly from a Makefile or CI pipeline (e.g.
+    /// `mytool 1` or `mytool format`) without needing an interactive terminal.
+    pub fn run_args(&self, args: &[String]) -> Result<(), String> {
+        match args.first() {
+            Some(selector) => self.dispatch_by_name(selector),
+            None => self.run(),
+        }
+    }
+
+    /// Run the script matching `name`, which may be a numeric script ID (as it appears in the
+    /// menu), a case-insensitive match against a script's description, or one of its
+    /// [`ScriptMeta::aliases`]. Returns an error if no script matches `name`, if `name` resolves
+    /// to a group rather than a script (groups can only be navigated interactively), or if the
+    /// matched script itself fails.
+    ///
+    /// A matched script that declares [`ScriptMeta::params`] has them collected the same way as
+    /// [`run_interactive`](ScriptManager::run_interactive), over the real standard input/output —
+    /// this still needs a real terminal (or real stdin/stdout) even though dispatching by name
+    /// does not, otherwise.
+    pub fn dispatch_by_name(&self, name: &str) -> Result<(), String> {
+        let script = if let Ok(id) = name.parse::<usize>() {
+            match self.entries.get(&id) {
+                Some(Entry::Script(script)) => script,
+                Some(Entry::Group(_)) => {
+                    return Err(format!("ID {} is a group and cannot be dispatched directly", id))
+                }
+                None => return Err(format!("no script found for ID {}", id)),
+            }
+        } else {
+            self.find_by_description(name)
+                .ok_or_else(|| format!("no script found matching '{}'", name))?
+                .1
+        };
+
+        Self::run_entry_script(script, &mut StdinSource, &mut std::io::stdout())
+    }
+
+    /// Iterate over the manager's entries in ID order. Useful for building alternate renderers
+    /// (a JSON listing of tasks, shell-completion generation, a different TUI) on top of `prsm`.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Entry<'a>)> {
+        self.entries.iter().map(|(id, entry)| (*id, entry))
+    }
+
+    /// The number of entries (scripts and groups, not recursively) held by this manager.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether this manager holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Look up the entry with the given ID.
+    pub fn get(&self, id: usize) -> Option<&Entry<'a>> {
+        self.entries.get(&id)
+    }
+
+    /// Find the first script (skipping groups) whose description or aliases match `name`, using
+    /// the same matching rules as [`dispatch_by_name`](ScriptManager::dispatch_by_name). Returns
+    /// the script's ID alongside a reference to it.
+    pub fn find_by_description(&self, name: &str) -> Option<(usize, &Script<'a>)> {
+        self.entries.iter().find_map(|(id, entry)| match entry {
+            Entry::Script(script) if script.matches_name(name) => Some((*id, script)),
+            _ => None,
+        })
+    }
+
+    /// Render the detailed help entry for the entry with the given ID, or `None` if no such
+    /// entry exists. Scripts include their category and long-form help text when present; groups
+    /// are rendered with a `(group)` marker.
+    pub fn help(&self, id: usize) -> Option<String> {
+        match self.entries.get(&id)? {
+            Entry::Script(script) => {
+                let mut entry = match script.meta.category {
+                    Some(category) => format!("[{}] {} ({})", id, script.description, category),
+                    None => format!("[{}] {}", id, script.description),
+                };
+
+                if let Some(help) = script.meta.help {
+                    entry.push_str("\n    ");
+                    entry.push_str(help);
+                }
+
+                if !script.meta.aliases.is_empty() {
+                    entry.push_str("\n    aliases: ");
+                    entry.push_str(&script.meta.aliases.join(", "));
+                }
+
+                if !script.meta.params.is_empty() {
+                    entry.push_str("\n    params: ");
+                    entry.push_str(
+                        &script
+                            .meta
+                            .params
+                            .iter()
+                            .map(|p| format!("{} ({})", p.name, p.prompt))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    );
+                }
+
+                Some(entry)
+            }
+            Entry::Group(group) => Some(format!("[{}] {} (group)", id, group.name)),
+        }
+    }
+
+    /// Print the detailed help view for every entry in the manager, in ID order.
+    pub fn print_help(&self) {
+        println!("{}\n", self.name);
+        for id in self.entries.keys() {
+            if let Some(entry) = self.help(*id) {
+                println!("{}\n", entry);
+            }
+        }
     }
 }
 
@@ -321,17 +947,116 @@ impl<'a> ScriptManager<'a> {
 /// assert_eq!(script.description, "This is a test function");
 /// assert_eq!(ok, ());  // Uh oh... where did my 200 response go?!
 /// ```
+///
+/// A script can also be given [`ScriptMeta`] (a longer help string, a category, and/or aliases)
+/// by following the function call with a `{ ... }` metadata block.
+///
+/// ```rust
+/// use prsm::prsm_script;
+///
+/// fn format() -> Result<(), std::io::Error> { Ok(()) }
+///
+/// let script = prsm_script!(
+///     "Format",
+///     format(),
+///     { category: "build", help: "Runs rustfmt over the workspace" }
+/// );
+///
+/// assert_eq!(script.description, "Format");
+/// ```
+///
+/// A script can also declare runtime-prompted [`ScriptMeta::params`] by leading the metadata
+/// block with a `params: &[ParamSpec { .. }, ..]` field — it must come *first*; any other key
+/// appearing before it (`{ category: "x", params: &[..] }`) falls through to the plain metadata
+/// arm below, which expects `$f` to be a call expression rather than a closure and fails to
+/// compile with an unrelated-looking error. When `params` leads the block, `$f` is instead a
+/// closure taking the collected `&[String]` (in declaration order) — this is what
+/// [`ScriptManager::run_interactive`] invokes once every parameter has been collected and
+/// validated.
+///
+/// ```rust
+/// use prsm::{prsm_script, ParamSpec};
+///
+/// fn parse_check(n: usize) -> Result<(), std::convert::Infallible> {
+///     assert_eq!(n, 42);
+///     Ok(())
+/// }
+///
+/// let script = prsm_script!(
+///     "Parse num",
+///     |params: &[String]| parse_check(params[0].parse().unwrap()),
+///     {
+///         params: &[ParamSpec {
+///             name: "n_str",
+///             prompt: "a number to check",
+///             validate: |s| s.parse::<usize>().map(|_| ()).map_err(|e| e.to_string()),
+///         }]
+///     }
+/// );
+///
+/// let ok = script.run_interactive(&["42".to_string()]).unwrap();
+/// assert_eq!(ok, ());
+/// ```
 #[macro_export]
 macro_rules! prsm_script {
     ($desc:literal, $f:expr) => {
         $crate::Script::new(
             $desc,
-            Box::new(move || {
+            Box::new(move |_params: &[String]| {
                 $f.map(|_| ())
                     .map_err(|e| Box::new(e) as Box<dyn $crate::PrsmDisplay>)
             }),
         )
     };
+
+    ($desc:literal, $f:expr, { params: $params:expr $(, $mkey:ident : $mval:expr)* $(,)? }) => {
+        $crate::Script::new(
+            $desc,
+            Box::new(move |__prsm_params: &[String]| {
+                ($f)(__prsm_params)
+                    .map(|_| ())
+                    .map_err(|e| Box::new(e) as Box<dyn $crate::PrsmDisplay>)
+            }),
+        )
+        .with_meta({
+            #[allow(clippy::needless_update)]
+            $crate::ScriptMeta {
+                params: $params,
+                $($mkey: $crate::prsm_meta_field!($mkey, $mval),)*
+                ..::std::default::Default::default()
+            }
+        })
+    };
+
+    ($desc:literal, $f:expr, { $($mkey:ident : $mval:expr),* $(,)? }) => {
+        $crate::prsm_script!($desc, $f).with_meta({
+            #[allow(clippy::needless_update)]
+            $crate::ScriptMeta {
+                $($mkey: $crate::prsm_meta_field!($mkey, $mval),)*
+                ..::std::default::Default::default()
+            }
+        })
+    };
+}
+
+/// Internal helper used by [`prsm_script`] to normalize a metadata field's value into the shape
+/// [`ScriptMeta`] expects (e.g. bare string literals become `Some(..)` for `help`/`category`).
+/// Not intended to be used directly.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! prsm_meta_field {
+    (help, $val:expr) => {
+        Some($val)
+    };
+    (category, $val:expr) => {
+        Some($val)
+    };
+    (aliases, $val:expr) => {
+        $val
+    };
+    (params, $val:expr) => {
+        $val
+    };
 }
 
 /// Generates a [`ScriptManager`].
@@ -388,20 +1113,87 @@ macro_rules! prsm_script {
 ///     [2] "Run func 2" => foo.mut_2() // Cannot have multiple mutable references!
 /// };
 /// ```
+///
+/// Entries can also be grouped into named submenus with a `group "Name" { ... }` block, nested
+/// to any depth, mixed freely alongside flat entries. See [`ScriptManager`]'s "Nested groups"
+/// section for how these are navigated interactively.
+///
+/// ```rust
+/// use prsm::prsm;
+///
+/// fn format() -> Result<(), std::io::Error> { Ok(()) }
+/// fn lint() -> Result<(), std::io::Error> { Ok(()) }
+/// fn deploy() -> Result<(), std::io::Error> { Ok(()) }
+///
+/// let sm = prsm! {
+///     [1] group "Build" {
+///         [1] "Format" => format(),
+///         [2] "Lint" => lint()
+///     },
+///     [2] "Deploy" => deploy()
+/// };
+///
+/// assert!(sm.help(1).unwrap().contains("Build (group)"));
+/// assert_eq!(sm.help(2).unwrap(), "[2] Deploy");
+/// ```
 #[macro_export]
 macro_rules! prsm {
-    ($([$idx:literal] $desc:literal => $f:expr),*) => {
-        $crate::ScriptManager::new(None, [$(($idx, $crate::prsm_script!($desc, $f))),*]
-            .into_iter()
-            .collect::<::std::collections::BTreeMap<usize, $crate::Script>>()
-        )
+    ($manager_name:ident { $($body:tt)* }) => {
+        $crate::ScriptManager::new(Some(stringify!($manager_name)), $crate::prsm_entries!($($body)*))
+    };
+
+    ($($body:tt)*) => {
+        $crate::ScriptManager::new(None, $crate::prsm_entries!($($body)*))
     };
+}
 
-    ($manager_name:ident { $([$idx:literal] $desc:literal => $f:expr),* }) => {
-        $crate::ScriptManager::new(Some(stringify!($manager_name)), [$(($idx, $crate::prsm_script!($desc, $f))),*]
+/// Internal tt-muncher used by [`prsm`] to parse a (possibly empty) comma-separated list of
+/// script/group entries into a `BTreeMap<usize, Entry>`. Not intended to be used directly.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! prsm_entries {
+    (@acc [$($acc:expr),*]) => {
+        [$($acc),*]
             .into_iter()
-            .collect::<::std::collections::BTreeMap<usize, $crate::Script>>()
-        )
+            .collect::<::std::collections::BTreeMap<usize, $crate::Entry>>()
+    };
+
+    (@acc [$($acc:expr),*] , $($rest:tt)*) => {
+        $crate::prsm_entries!(@acc [$($acc),*] $($rest)*)
+    };
+
+    (@acc [$($acc:expr),*] [$idx:literal] group $gname:literal { $($inner:tt)* } $($rest:tt)*) => {
+        $crate::prsm_entries!(@acc [$($acc,)* ($idx, $crate::Entry::Group(
+            $crate::ScriptManager::new(Some($gname), $crate::prsm_entries!($($inner)*))
+        ))] $($rest)*)
+    };
+
+    (@acc [$($acc:expr),*] [$idx:literal] $desc:literal { $($mkey:ident : $mval:expr),* $(,)? } => $f:expr , $($rest:tt)*) => {
+        $crate::prsm_entries!(@acc [$($acc,)* ($idx, $crate::Entry::Script(
+            $crate::prsm_script!($desc, $f, { $($mkey: $mval),* })
+        ))] $($rest)*)
+    };
+
+    (@acc [$($acc:expr),*] [$idx:literal] $desc:literal { $($mkey:ident : $mval:expr),* $(,)? } => $f:expr) => {
+        $crate::prsm_entries!(@acc [$($acc,)* ($idx, $crate::Entry::Script(
+            $crate::prsm_script!($desc, $f, { $($mkey: $mval),* })
+        ))])
+    };
+
+    (@acc [$($acc:expr),*] [$idx:literal] $desc:literal => $f:expr , $($rest:tt)*) => {
+        $crate::prsm_entries!(@acc [$($acc,)* ($idx, $crate::Entry::Script(
+            $crate::prsm_script!($desc, $f)
+        ))] $($rest)*)
+    };
+
+    (@acc [$($acc:expr),*] [$idx:literal] $desc:literal => $f:expr) => {
+        $crate::prsm_entries!(@acc [$($acc,)* ($idx, $crate::Entry::Script(
+            $crate::prsm_script!($desc, $f)
+        ))])
+    };
+
+    ($($body:tt)*) => {
+        $crate::prsm_entries!(@acc [] $($body)*)
     };
 }
 
@@ -411,6 +1203,16 @@ mod tests {
 
     use super::*;
 
+    /// Run the script at `idx`, panicking if no such script exists (e.g. the ID is unused or
+    /// names a group). Built entirely on [`ScriptManager`]'s public introspection API.
+    fn run_script(sm: &ScriptManager, idx: usize) -> Result<(), String> {
+        match sm.get(idx) {
+            Some(Entry::Script(script)) => script.run().map_err(|e| format!("{}", e)),
+            Some(Entry::Group(_)) => panic!("entry {} is a group, not a script", idx),
+            None => panic!("no entry with ID {}", idx),
+        }
+    }
+
     #[test]
     fn prsm_manager_default_name() {
         let x = || -> Result<(), usize> { Ok(()) };
@@ -433,6 +1235,210 @@ mod tests {
         assert_eq!(sm.name, "TestManager");
     }
 
+    #[test]
+    fn prsm_script_metadata() {
+        let x = || -> Result<(), usize> { Ok(()) };
+        let y = || -> Result<(), usize> { Ok(()) };
+
+        let sm = prsm! {
+            [1] "Test x" { category: "build", help: "runs x", aliases: &["ex"] } => x(),
+            [2] "Test y" => y()
+        };
+
+        assert_eq!(
+            sm.help(1).unwrap(),
+            "[1] Test x (build)\n    runs x\n    aliases: ex"
+        );
+        assert_eq!(sm.help(2).unwrap(), "[2] Test y");
+        assert!(sm.help(3).is_none());
+
+        assert!(matches!(sm.dispatch_by_name("ex"), Ok(())));
+    }
+
+    const N_STR_PARAM: ParamSpec = ParamSpec {
+        name: "n_str",
+        prompt: "a number to check",
+        validate: |s| s.parse::<usize>().map(|_| ()).map_err(|e| e.to_string()),
+    };
+
+    #[test]
+    fn prsm_script_param_help() {
+        fn parse_check(_n: usize) -> Result<(), usize> {
+            Ok(())
+        }
+
+        let sm = prsm! {
+            [1] "Parse num" {
+                params: &[N_STR_PARAM]
+            } => |params: &[String]| parse_check(params[0].parse().unwrap())
+        };
+
+        assert_eq!(
+            sm.help(1).unwrap(),
+            "[1] Parse num\n    params: n_str (a number to check)"
+        );
+    }
+
+    #[test]
+    fn prsm_script_run_interactive() {
+        fn parse_check(n: usize) -> Result<(), usize> {
+            if n == 42 {
+                Ok(())
+            } else {
+                Err(n)
+            }
+        }
+
+        let sm = prsm! {
+            [1] "Parse num" {
+                params: &[N_STR_PARAM]
+            } => |params: &[String]| parse_check(params[0].parse().unwrap())
+        };
+
+        let input = std::io::Cursor::new(b"not a number\n42\n".to_vec());
+        let mut output = Vec::new();
+        let result = sm.run_interactive_with(1, input, &mut output);
+
+        assert!(matches!(result, Ok(())));
+        let rendered = String::from_utf8(output).unwrap();
+        assert!(rendered.contains("n_str (a number to check): "));
+        assert!(rendered.contains("invalid value for 'n_str': invalid digit found in string"));
+
+        assert!(sm
+            .run_interactive_with(2, std::io::Cursor::new(Vec::new()), Vec::new())
+            .unwrap_err()
+            .contains("no script found"));
+    }
+
+    #[test]
+    fn prsm_script_run_interactive_eof() {
+        fn parse_check(_n: usize) -> Result<(), usize> {
+            Ok(())
+        }
+
+        let sm = prsm! {
+            [1] "Parse num" {
+                params: &[N_STR_PARAM]
+            } => |params: &[String]| parse_check(params[0].parse().unwrap())
+        };
+
+        let input = std::io::Cursor::new(Vec::new());
+        let result = sm.run_interactive_with(1, input, Vec::new());
+
+        assert!(result.unwrap_err().contains("input closed"));
+    }
+
+    #[test]
+    fn prsm_script_run_rejects_declared_params() {
+        fn parse_check(_n: usize) -> Result<(), usize> {
+            Ok(())
+        }
+
+        let sm = prsm! {
+            [1] "Parse num" {
+                params: &[N_STR_PARAM]
+            } => |params: &[String]| parse_check(params[0].parse().unwrap())
+        };
+
+        match sm.get(1) {
+            Some(Entry::Script(script)) => {
+                let err = script.run().unwrap_err();
+                assert!(format!("{}", err).contains("run_interactive"));
+            }
+            _ => panic!("expected a script at ID 1"),
+        }
+    }
+
+    #[test]
+    fn prsm_nested_groups() {
+        let x = || -> Result<(), usize> { Ok(()) };
+        let y = || -> Result<(), usize> { Ok(()) };
+        let z = || -> Result<(), usize> { Ok(()) };
+
+        let sm = prsm! {
+            [1] group "Build" {
+                [1] "Test x" => x(),
+                [2] "Test y" => y()
+            },
+            [2] "Test z" => z()
+        };
+
+        assert_eq!(sm.help(1).unwrap(), "[1] Build (group)");
+        assert_eq!(sm.help(2).unwrap(), "[2] Test z");
+        assert!(matches!(run_script(&sm, 2), Ok(())));
+
+        assert!(sm
+            .dispatch_by_name("1")
+            .unwrap_err()
+            .contains("cannot be dispatched directly"));
+    }
+
+    #[test]
+    fn prsm_introspection() {
+        let x = || -> Result<(), usize> { Ok(()) };
+        let y = || -> Result<(), usize> { Ok(()) };
+
+        let sm = prsm! {
+            [1] "Test x" { aliases: &["ex"] } => x(),
+            [2] "Test y" => y()
+        };
+
+        assert_eq!(sm.len(), 2);
+        assert!(!sm.is_empty());
+
+        assert!(matches!(sm.get(1), Some(Entry::Script(_))));
+        assert!(sm.get(3).is_none());
+
+        let descriptions: Vec<&str> = sm
+            .iter()
+            .map(|(_, entry)| match entry {
+                Entry::Script(script) => script.description,
+                Entry::Group(group) => group.name,
+            })
+            .collect();
+        assert_eq!(descriptions, vec!["Test x", "Test y"]);
+
+        let (id, script) = sm.find_by_description("ex").unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(script.description, "Test x");
+        assert!(sm.find_by_description("missing").is_none());
+    }
+
+    #[test]
+    fn prsm_run_with_scripted_input() {
+        let x = || -> Result<(), usize> { Ok(()) };
+        let sm = prsm! { [1] "Test x" => x() };
+
+        let input = std::io::Cursor::new(b"1\n".to_vec());
+        let mut output = Vec::new();
+        let result = sm.run_with(input, &mut output);
+
+        assert!(matches!(result, Ok(())));
+        let rendered = String::from_utf8(output).unwrap();
+        assert!(rendered.contains("Enter ID: "));
+    }
+
+    #[test]
+    fn prsm_run_loop_with_scripted_input() {
+        let x = || -> Result<(), usize> { Ok(()) };
+        let y = || -> Result<(), usize> { Ok(()) };
+
+        let sm = prsm! {
+            [1] group "Build" {
+                [1] "Test x" => x()
+            },
+            [2] "Test y" => y()
+        };
+
+        let input = std::io::Cursor::new(b"nope\n1\n1\nq\n2\nq\n".to_vec());
+        let mut output = Vec::new();
+        let result = sm.run_loop_with(input, &mut output);
+
+        assert!(matches!(result, Ok(())));
+        let rendered = String::from_utf8(output).unwrap();
+        assert!(rendered.contains("'nope' is not a valid ID."));
+    }
+
     mod external_module {
         pub fn x() -> Result<(), usize> {
             Ok(())
@@ -455,10 +1461,10 @@ mod tests {
             [3] "z" => external_module::z(0)
         };
 
-        assert!(matches!(sm.run_script(1), Ok(())));
-        assert!(matches!(sm.run_script(2), Ok(())));
-        assert!(matches!(sm.run_script(3), Err(e) if e == "3"));
-        assert_eq!(sm.scripts.len(), 3);
+        assert!(matches!(run_script(&sm, 1), Ok(())));
+        assert!(matches!(run_script(&sm, 2), Ok(())));
+        assert!(matches!(run_script(&sm, 3), Err(e) if e == "3"));
+        assert_eq!(sm.entries.len(), 3);
     }
 
     #[test]
@@ -481,10 +1487,10 @@ mod tests {
             [3] "z" => z(0)
         };
 
-        assert!(matches!(sm.run_script(1), Ok(())));
-        assert!(matches!(sm.run_script(2), Ok(())));
-        assert!(matches!(sm.run_script(3), Err(e) if e == "3"));
-        assert_eq!(sm.scripts.len(), 3);
+        assert!(matches!(run_script(&sm, 1), Ok(())));
+        assert!(matches!(run_script(&sm, 2), Ok(())));
+        assert!(matches!(run_script(&sm, 3), Err(e) if e == "3"));
+        assert_eq!(sm.entries.len(), 3);
     }
 
     #[test]
@@ -499,10 +1505,10 @@ mod tests {
             [3] "z" => z(0)
         };
 
-        assert!(matches!(sm.run_script(1), Ok(())));
-        assert!(matches!(sm.run_script(2), Ok(())));
-        assert!(matches!(sm.run_script(3), Err(e) if e == "3"));
-        assert_eq!(sm.scripts.len(), 3);
+        assert!(matches!(run_script(&sm, 1), Ok(())));
+        assert!(matches!(run_script(&sm, 2), Ok(())));
+        assert!(matches!(run_script(&sm, 3), Err(e) if e == "3"));
+        assert_eq!(sm.entries.len(), 3);
     }
 
     #[test]
@@ -519,10 +1525,10 @@ mod tests {
             [3] "z" => z(5)
         };
 
-        assert!(matches!(sm.run_script(1), Ok(())));
-        assert!(matches!(sm.run_script(2), Ok(())));
-        assert!(matches!(sm.run_script(3), Err(e) if e == "7"));
-        assert_eq!(sm.scripts.len(), 3);
+        assert!(matches!(run_script(&sm, 1), Ok(())));
+        assert!(matches!(run_script(&sm, 2), Ok(())));
+        assert!(matches!(run_script(&sm, 3), Err(e) if e == "7"));
+        assert_eq!(sm.entries.len(), 3);
     }
 
     #[test]